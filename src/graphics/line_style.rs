@@ -0,0 +1,161 @@
+// Copyright (C) 2022 Alexander Rolley
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::error;
+use std::fmt;
+
+use crate::util::format_number;
+use crate::util::mm;
+
+/// The shape at the open ends of a stroked line. The discriminants are the operands of the `J` operator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LineCap {
+    Butt = 0,
+    Round = 1,
+    ProjectingSquare = 2,
+}
+
+/// The shape at the corners where two stroked segments meet. The discriminants are the operands of the `j` operator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LineJoin {
+    Miter = 0,
+    Round = 1,
+    Bevel = 2,
+}
+
+/// The dash pattern, line cap and line join used when stroking lines, in millimeters.
+#[derive(Debug, Clone)]
+pub struct LineStyle {
+    pub dash_pattern: Vec<mm::MM>,
+    pub dash_phase: mm::MM,
+    pub line_cap: LineCap,
+    pub line_join: LineJoin,
+}
+
+impl LineStyle {
+    /// Creates a line style, rejecting dash patterns that the PDF specification does not allow:
+    /// lengths must not be negative and must not all be zero.
+    pub fn new(dash_pattern: Vec<mm::MM>,
+               dash_phase: impl Into<mm::MM>,
+               line_cap: LineCap,
+               line_join: LineJoin) -> Result<LineStyle, LineStyleError> {
+        if dash_pattern.iter().any(|length| length.millimetres().is_nan() || length.millimetres() < 0.0) {
+            return Err(LineStyleError::InvalidDashLength);
+        }
+        if !dash_pattern.is_empty() && dash_pattern.iter().all(|length| length.millimetres() == 0.0) {
+            return Err(LineStyleError::ZeroDashPattern);
+        }
+
+        Ok(LineStyle {
+            dash_pattern,
+            dash_phase: dash_phase.into(),
+            line_cap,
+            line_join,
+        })
+    }
+
+    /// A solid line with butt caps and miter joins, which is the PDF default.
+    pub fn solid() -> LineStyle {
+        LineStyle {
+            dash_pattern: Vec::new(),
+            dash_phase: mm::MM::from(0),
+            line_cap: LineCap::Butt,
+            line_join: LineJoin::Miter,
+        }
+    }
+
+    /// A line of dashes of the given length separated by gaps of the given length.
+    pub fn dashed(dash: impl Into<mm::MM>, gap: impl Into<mm::MM>) -> Result<LineStyle, LineStyleError> {
+        LineStyle::new(vec![dash.into(), gap.into()], 0, LineCap::Butt, LineJoin::Miter)
+    }
+
+    /// A line of round dots spaced the given distance apart.
+    pub fn dotted(spacing: impl Into<mm::MM>) -> Result<LineStyle, LineStyleError> {
+        // Zero-length dashes with round caps are drawn as dots the diameter of the line width.
+        LineStyle::new(vec![mm::MM::from(0), spacing.into()], 0, LineCap::Round, LineJoin::Round)
+    }
+
+    /// Returns the `d`, `J` and `j` operators that set this style in a content stream.
+    pub fn to_operators(&self) -> String {
+        let dash_array: Vec<String> = self.dash_pattern.iter()
+            .map(|length| format_number(length.points()))
+            .collect();
+
+        format!("[{}] {} d\n{} J\n{} j",
+                dash_array.join(" "),
+                format_number(self.dash_phase.points()),
+                self.line_cap as u8,
+                self.line_join as u8)
+    }
+}
+
+/// The error returned when a dash pattern is not allowed by the PDF specification.
+#[derive(Debug, PartialEq)]
+pub enum LineStyleError {
+    /// A dash or gap length is negative or not a number.
+    InvalidDashLength,
+    /// Every dash and gap length is zero.
+    ZeroDashPattern,
+}
+
+impl fmt::Display for LineStyleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LineStyleError::InvalidDashLength => write!(f, "dash lengths must be zero or positive numbers"),
+            LineStyleError::ZeroDashPattern => write!(f, "dash lengths must not all be zero"),
+        }
+    }
+}
+
+impl error::Error for LineStyleError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dashed_emits_dash_pattern() {
+        assert_eq!(LineStyle::dashed(25.4, 12.7).unwrap().to_operators(), "[72 36] 0 d\n0 J\n0 j");
+    }
+
+    #[test]
+    fn solid_emits_empty_dash_pattern() {
+        assert_eq!(LineStyle::solid().to_operators(), "[] 0 d\n0 J\n0 j");
+    }
+
+    #[test]
+    fn dotted_uses_round_caps_and_joins() {
+        assert_eq!(LineStyle::dotted(25.4).unwrap().to_operators(), "[0 72] 0 d\n1 J\n1 j");
+    }
+
+    #[test]
+    fn rejects_all_zero_dash_patterns() {
+        assert_eq!(LineStyle::dotted(0).unwrap_err(), LineStyleError::ZeroDashPattern);
+        assert_eq!(LineStyle::dashed(0, 0).unwrap_err(), LineStyleError::ZeroDashPattern);
+    }
+
+    #[test]
+    fn rejects_negative_and_nan_dash_lengths() {
+        assert_eq!(LineStyle::dashed(-2, 1).unwrap_err(), LineStyleError::InvalidDashLength);
+        assert_eq!(LineStyle::dashed(2, f64::NAN).unwrap_err(), LineStyleError::InvalidDashLength);
+    }
+
+    #[test]
+    fn cap_and_join_operands_match_the_specification() {
+        let style: LineStyle = LineStyle::new(Vec::new(), 0, LineCap::ProjectingSquare, LineJoin::Bevel).unwrap();
+
+        assert_eq!(style.to_operators(), "[] 0 d\n2 J\n2 j");
+    }
+}
@@ -0,0 +1,288 @@
+// Copyright (C) 2022 Alexander Rolley
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::util::format_number;
+use crate::util::mm;
+use crate::util::position;
+
+// The distance of a Bézier control point from the end point, as a fraction of the radius, for approximating a quarter circle.
+const KAPPA: f64 = 0.5522847498;
+
+/// A single segment of a path.
+#[derive(Debug, Clone)]
+pub enum PathSegment {
+    MoveTo(mm::MM, mm::MM),
+    LineTo(mm::MM, mm::MM),
+    /// A cubic Bézier curve through two control points to an end point.
+    CurveTo(mm::MM, mm::MM, mm::MM, mm::MM, mm::MM, mm::MM),
+    Close,
+}
+
+/// A path made of lines and curves, in millimeters.
+#[derive(Debug, Clone, Default)]
+pub struct Path {
+    pub segments: Vec<PathSegment>,
+}
+
+impl Path {
+    pub fn new() -> Path {
+        Path {
+            segments: Vec::new(),
+        }
+    }
+
+    /// A closed rectangle with its lower left corner at `position` and corners rounded with the given radius.
+    /// The radius is limited to between zero and half the shorter side. A negative width or height extends the
+    /// rectangle to the left of or below `position`.
+    pub fn rounded_rectangle(position: &position::Position,
+                             width: impl Into<mm::MM>,
+                             height: impl Into<mm::MM>,
+                             corner_radius: impl Into<mm::MM>) -> Path {
+        let width: f64 = width.into().millimetres();
+        let height: f64 = height.into().millimetres();
+
+        // Normalise to a positive width and height so the corners are always traced counter-clockwise.
+        let x: f64 = position.x_coordinate.millimetres() + width.min(0.0);
+        let y: f64 = position.y_coordinate.millimetres() + height.min(0.0);
+        let w: f64 = width.abs();
+        let h: f64 = height.abs();
+        let r: f64 = corner_radius.into().millimetres().min(w / 2.0).min(h / 2.0).max(0.0);
+        let k: f64 = r * KAPPA;
+
+        // When the radius is half a side, the corners meet and the straight edge between them has no length.
+        let has_horizontal_edges: bool = w - 2.0 * r > 0.0;
+        let has_vertical_edges: bool = h - 2.0 * r > 0.0;
+
+        let mut path: Path = Path::new();
+        path.move_to(x + r, y);
+        if has_horizontal_edges {
+            path.line_to(x + w - r, y);
+        }
+        path.curve_to(x + w - r + k, y, x + w, y + r - k, x + w, y + r);
+        if has_vertical_edges {
+            path.line_to(x + w, y + h - r);
+        }
+        path.curve_to(x + w, y + h - r + k, x + w - r + k, y + h, x + w - r, y + h);
+        if has_horizontal_edges {
+            path.line_to(x + r, y + h);
+        }
+        path.curve_to(x + r - k, y + h, x, y + h - r + k, x, y + h - r);
+        if has_vertical_edges {
+            path.line_to(x, y + r);
+        }
+        path.curve_to(x, y + r - k, x + r - k, y, x + r, y)
+            .close();
+        path
+    }
+
+    /// A closed ellipse around `center` with the given horizontal and vertical radii.
+    pub fn ellipse(center: &position::Position,
+                   rx: impl Into<mm::MM>,
+                   ry: impl Into<mm::MM>) -> Path {
+        let x: f64 = center.x_coordinate.millimetres();
+        let y: f64 = center.y_coordinate.millimetres();
+        let rx: f64 = rx.into().millimetres();
+        let ry: f64 = ry.into().millimetres();
+        let kx: f64 = rx * KAPPA;
+        let ky: f64 = ry * KAPPA;
+
+        let mut path: Path = Path::new();
+        path.move_to(x + rx, y)
+            .curve_to(x + rx, y + ky, x + kx, y + ry, x, y + ry)
+            .curve_to(x - kx, y + ry, x - rx, y + ky, x - rx, y)
+            .curve_to(x - rx, y - ky, x - kx, y - ry, x, y - ry)
+            .curve_to(x + kx, y - ry, x + rx, y - ky, x + rx, y)
+            .close();
+        path
+    }
+
+    /// A closed circle around `center` with the given radius.
+    pub fn circle(center: &position::Position, radius: impl Into<mm::MM>) -> Path {
+        let radius: mm::MM = radius.into();
+
+        Path::ellipse(center, radius, radius)
+    }
+
+    /// Begins a new subpath at the given point.
+    pub fn move_to(&mut self, x: impl Into<mm::MM>, y: impl Into<mm::MM>) -> &mut Path {
+        self.segments.push(PathSegment::MoveTo(x.into(), y.into()));
+        self
+    }
+
+    /// Appends a straight line from the current point to the given point.
+    pub fn line_to(&mut self, x: impl Into<mm::MM>, y: impl Into<mm::MM>) -> &mut Path {
+        self.segments.push(PathSegment::LineTo(x.into(), y.into()));
+        self
+    }
+
+    /// Appends a cubic Bézier curve from the current point to `(x3, y3)`.
+    pub fn curve_to(&mut self,
+                    x1: impl Into<mm::MM>,
+                    y1: impl Into<mm::MM>,
+                    x2: impl Into<mm::MM>,
+                    y2: impl Into<mm::MM>,
+                    x3: impl Into<mm::MM>,
+                    y3: impl Into<mm::MM>) -> &mut Path {
+        self.segments.push(PathSegment::CurveTo(x1.into(), y1.into(),
+                                                x2.into(), y2.into(),
+                                                x3.into(), y3.into()));
+        self
+    }
+
+    /// Closes the current subpath with a straight line back to its start.
+    pub fn close(&mut self) -> &mut Path {
+        self.segments.push(PathSegment::Close);
+        self
+    }
+
+    /// Returns the path construction operators followed by the paint operator.
+    /// A path that is neither stroked nor filled is ended with `n`, and an empty path produces no operators.
+    pub fn to_operators(&self, stroke: bool, fill: bool) -> String {
+        // A paint operator without a current path is invalid content.
+        if self.segments.is_empty() {
+            return String::new();
+        }
+
+        let mut operators: Vec<String> = self.segments.iter().map(|segment| match segment {
+            PathSegment::MoveTo(x, y) => format!("{} m", format_points(&[x, y])),
+            PathSegment::LineTo(x, y) => format!("{} l", format_points(&[x, y])),
+            PathSegment::CurveTo(x1, y1, x2, y2, x3, y3) => {
+                format!("{} c", format_points(&[x1, y1, x2, y2, x3, y3]))
+            }
+            PathSegment::Close => String::from("h"),
+        }).collect();
+
+        let paint_operator: &str = match (stroke, fill) {
+            (true, true) => "B",
+            (true, false) => "S",
+            (false, true) => "f",
+            (false, false) => "n",
+        };
+        operators.push(String::from(paint_operator));
+
+        operators.join("\n")
+    }
+}
+
+fn format_points(coordinates: &[&mm::MM]) -> String {
+    let operands: Vec<String> = coordinates.iter()
+        .map(|coordinate| format_number(coordinate.points()))
+        .collect();
+
+    operands.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn triangle() -> Path {
+        let mut path: Path = Path::new();
+        path.move_to(0, 0)
+            .line_to(25.4, 0)
+            .line_to(12.7, 25.4)
+            .close();
+        path
+    }
+
+    #[test]
+    fn triangle_closes_and_strokes() {
+        assert_eq!(triangle().to_operators(true, false), "0 0 m\n72 0 l\n36 72 l\nh\nS");
+    }
+
+    #[test]
+    fn paint_operator_follows_stroke_and_fill() {
+        assert!(triangle().to_operators(true, true).ends_with("h\nB"));
+        assert!(triangle().to_operators(true, false).ends_with("h\nS"));
+        assert!(triangle().to_operators(false, true).ends_with("h\nf"));
+        assert!(triangle().to_operators(false, false).ends_with("h\nn"));
+    }
+
+    #[test]
+    fn curve_to_emits_c_operator() {
+        let mut path: Path = Path::new();
+        path.move_to(0, 0).curve_to(0, 25.4, 25.4, 25.4, 25.4, 0);
+
+        assert_eq!(path.to_operators(true, false), "0 0 m\n0 72 72 72 72 0 c\nS");
+    }
+
+    #[test]
+    fn rounded_rectangle_has_four_corners_and_closes() {
+        let operators: String = Path::rounded_rectangle(&position::Position::new(0, 0, 0), 50, 20, 5)
+            .to_operators(true, false);
+
+        assert!(operators.starts_with("14.17323 0 m\n"));
+        assert_eq!(operators.matches(" c\n").count(), 4);
+        assert_eq!(operators.matches(" l\n").count(), 4);
+        assert!(operators.ends_with("h\nS"));
+    }
+
+    #[test]
+    fn rounded_rectangle_skips_zero_length_edges() {
+        // The radius is clamped to 10mm, which leaves no straight vertical edges on a 20mm tall rectangle.
+        let operators: String = Path::rounded_rectangle(&position::Position::new(0, 0, 0), 50, 20, 30)
+            .to_operators(true, false);
+
+        assert_eq!(operators.matches(" c\n").count(), 4);
+        assert_eq!(operators.matches(" l\n").count(), 2);
+        assert!(!operators.contains("141.73228 28.34646 l"));
+    }
+
+    #[test]
+    fn rounded_rectangle_clamps_negative_radius_to_zero() {
+        let negative: Path = Path::rounded_rectangle(&position::Position::new(0, 0, 0), 50, 20, -5);
+        let square: Path = Path::rounded_rectangle(&position::Position::new(0, 0, 0), 50, 20, 0);
+
+        assert_eq!(negative.to_operators(true, false), square.to_operators(true, false));
+        assert!(negative.to_operators(true, false).starts_with("0 0 m\n"));
+    }
+
+    #[test]
+    fn ellipse_has_four_curves_and_closes() {
+        let operators: String = Path::ellipse(&position::Position::new(25.4, 50.8, 0), 12.7, 25.4)
+            .to_operators(false, true);
+
+        // Starts at (cx + rx, cy).
+        assert!(operators.starts_with("108 144 m\n"));
+        assert_eq!(operators.matches(" c\n").count(), 4);
+        assert!(operators.ends_with(" 108 144 c\nh\nf"));
+    }
+
+    #[test]
+    fn circle_is_an_ellipse_with_equal_radii() {
+        let center: position::Position = position::Position::new(10, 10, 0);
+
+        assert_eq!(Path::circle(&center, 5).to_operators(true, true),
+                   Path::ellipse(&center, 5, 5).to_operators(true, true));
+    }
+
+    #[test]
+    fn empty_path_emits_nothing() {
+        assert_eq!(Path::new().to_operators(true, false), "");
+        assert_eq!(Path::new().to_operators(false, false), "");
+    }
+
+    #[test]
+    fn rounded_rectangle_with_negative_width_extends_left() {
+        let negative: Path = Path::rounded_rectangle(&position::Position::new(0, 0, 0), -10, 20, 5);
+        let positive: Path = Path::rounded_rectangle(&position::Position::new(-10, 0, 0), 10, 20, 5);
+        let operators: String = negative.to_operators(true, false);
+
+        assert_eq!(operators, positive.to_operators(true, false));
+        assert!(operators.starts_with("-14.17323 0 m\n"));
+        assert!(!operators.contains(" -14.17323 l"));
+        assert_eq!(operators.matches(" c\n").count(), 4);
+    }
+}
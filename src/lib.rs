@@ -13,15 +13,29 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+//! Create PDFs in rust.
+//!
+//! Lengths are given in millimeters, either as an [`MM`] or as any number that converts into one:
+//!
+//! ```
+//! use rc_pdf::{MM, Position};
+//!
+//! let position: Position = Position::new(MM::from(10), 20, 0);
+//!
+//! assert_eq!(position.x_coordinate.millimetres(), 10.0);
+//! assert_eq!(position.y_coordinate.to_string(), "20mm");
+//! ```
+
+mod graphics;
 mod paper;
 mod util;
 
-struct Document {
-    file_name: String,
-    pdf_version: f32,
-    is_binary_file: bool,
-    pages: Vec<Page>,
-    xref_table: Vec<ObjectPosition>,
-    trailer: String,
-    document_start: u64,
-}
+pub use util::mm::MM;
+pub use util::position::Position;
+pub use util::size::Size;
+pub use util::margins::{MarginError, Margins};
+pub use paper::{PaperOrientation, PaperSize};
+pub use util::rotation::Rotation;
+pub use util::matrix::Matrix;
+pub use graphics::line_style::{LineCap, LineJoin, LineStyle, LineStyleError};
+pub use graphics::path::{Path, PathSegment};
@@ -13,13 +13,12 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::util::mm;
 use crate::util::size;
-use crate::util::margins;
 use crate::util::rotation;
 
 /// The paper size of a page in the PDF document.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PaperSize {
     A0,
     A1,
@@ -28,20 +27,45 @@ pub enum PaperSize {
     A4,
     A5,
     A6,
+    A7,
+    A8,
+    B4,
+    B5,
+    C5,
+    C6,
+    DL,
     Executive1,
     Executive2,
     Executive3,
+    HalfLetter,
+    Ledger,
     Legal,
     Letter,
+    Tabloid,
 }
 
 /// The paper orientation of a page in the PDF document.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PaperOrientation {
     Portrait,
     Landscape,
 }
 
+impl PaperSize {
+    /// Returns the width and height in PDF points, swapped for landscape orientation.
+    pub fn points(&self, orientation: &PaperOrientation) -> (f64, f64) {
+        let paper_size: size::Size = size::Size::from(*self);
+        let width: f64 = paper_size.width.points();
+        let height: f64 = paper_size.height.points();
+
+        match orientation {
+            PaperOrientation::Portrait => (width, height),
+            PaperOrientation::Landscape => (height, width),
+        }
+    }
+}
+
 impl From<PaperSize> for size::Size {
     fn from(paper_size: PaperSize) -> size::Size {
         match paper_size {
@@ -52,11 +76,21 @@ impl From<PaperSize> for size::Size {
             PaperSize::A4 => size::Size::new(210, 297),
             PaperSize::A5 => size::Size::new(148, 210),
             PaperSize::A6 => size::Size::new(105, 148),
+            PaperSize::A7 => size::Size::new(74, 105),
+            PaperSize::A8 => size::Size::new(52, 74),
+            PaperSize::B4 => size::Size::new(250, 353),
+            PaperSize::B5 => size::Size::new(176, 250),
+            PaperSize::C5 => size::Size::new(162, 229),              // envelope
+            PaperSize::C6 => size::Size::new(114, 162),              // envelope
+            PaperSize::DL => size::Size::new(110, 220),              // envelope
             PaperSize::Executive1 => size::Size::new(177.8, 266.7),  // 7 x 10.5 inches
             PaperSize::Executive2 => size::Size::new(184.15, 266.7), // 7.25 x 10.5 inches
             PaperSize::Executive3 => size::Size::new(190.5, 266.7),  // 7.5 x 10.5 inches
+            PaperSize::HalfLetter => size::Size::new(139.7, 215.9),  // 5.5 x 8.5 inches
+            PaperSize::Ledger => size::Size::new(431.8, 279.4),      // 17 x 11 inches
             PaperSize::Legal => size::Size::new(215.9, 355.6),       // 8.5 x 14 inches
             PaperSize::Letter => size::Size::new(215.9, 279.4),      // 8.5 x 11 inches
+            PaperSize::Tabloid => size::Size::new(279.4, 431.8),     // 11 x 17 inches
         }
     }
 }
@@ -69,3 +103,44 @@ impl From<PaperOrientation> for rotation::Rotation {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dimensions(paper_size: PaperSize) -> (f64, f64) {
+        let paper_size: size::Size = size::Size::from(paper_size);
+
+        (paper_size.width.millimetres(), paper_size.height.millimetres())
+    }
+
+    #[test]
+    fn tabloid_is_11_by_17_inches() {
+        assert_eq!(dimensions(PaperSize::Tabloid), (279.4, 431.8));
+        assert_eq!(dimensions(PaperSize::Ledger), (431.8, 279.4));
+    }
+
+    #[test]
+    fn dl_envelope_is_110_by_220_millimeters() {
+        assert_eq!(dimensions(PaperSize::DL), (110.0, 220.0));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trips_through_json() {
+        let json: String = serde_json::to_string(&PaperSize::Tabloid).unwrap();
+
+        assert_eq!(json, r#""Tabloid""#);
+        assert!(matches!(serde_json::from_str(&json).unwrap(), PaperSize::Tabloid));
+        assert!(matches!(serde_json::from_str(r#""Landscape""#).unwrap(), PaperOrientation::Landscape));
+    }
+
+    #[test]
+    fn a4_points_follow_orientation() {
+        let (width, height): (f64, f64) = PaperSize::A4.points(&PaperOrientation::Portrait);
+
+        assert!((width - 595.28).abs() < 0.01);
+        assert!((height - 841.89).abs() < 0.01);
+        assert_eq!(PaperSize::A4.points(&PaperOrientation::Landscape), (height, width));
+    }
+}
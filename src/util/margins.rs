@@ -13,10 +13,15 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+use std::error;
+use std::fmt;
+
 use super::mm;
+use super::size;
 
 /// The margins of an element in the PDF document, in millimeters.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Margins {
     pub left_margin: mm::MM,
     pub right_margin: mm::MM,
@@ -36,4 +41,137 @@ impl Margins {
             bottom_margin: bottom_margin.into(),
         }
     }
+
+    /// Returns the left margin in PDF points.
+    pub fn left_points(&self) -> f64 {
+        self.left_margin.points()
+    }
+
+    /// Returns the right margin in PDF points.
+    pub fn right_points(&self) -> f64 {
+        self.right_margin.points()
+    }
+
+    /// Returns the top margin in PDF points.
+    pub fn top_points(&self) -> f64 {
+        self.top_margin.points()
+    }
+
+    /// Returns the bottom margin in PDF points.
+    pub fn bottom_points(&self) -> f64 {
+        self.bottom_margin.points()
+    }
+
+    /// Checks that the margins leave a non-empty content area on a page of the given size.
+    /// Negative and NaN margins are rejected, as are page sizes that are NaN or infinite.
+    pub fn validate(&self, page: &size::Size) -> Result<(), MarginError> {
+        let sides: [&mm::MM; 4] = [&self.left_margin, &self.right_margin, &self.top_margin, &self.bottom_margin];
+
+        if sides.iter().any(|side| side.millimetres().is_nan() || side.millimetres() < 0.0) {
+            return Err(MarginError::InvalidMargin);
+        }
+
+        let horizontal: f64 = self.left_margin.millimetres() + self.right_margin.millimetres();
+        let vertical: f64 = self.top_margin.millimetres() + self.bottom_margin.millimetres();
+
+        if !page.width.millimetres().is_finite() || !page.height.millimetres().is_finite() {
+            return Err(MarginError::InvalidPageSize);
+        }
+
+        if horizontal >= page.width.millimetres() {
+            Err(MarginError::ExceedsWidth)
+        } else if vertical >= page.height.millimetres() {
+            Err(MarginError::ExceedsHeight)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// The error returned when margins do not fit on a page.
+#[derive(Debug, PartialEq)]
+pub enum MarginError {
+    /// A margin is negative or not a number.
+    InvalidMargin,
+    /// The page width or height is NaN or infinite.
+    InvalidPageSize,
+    /// The left and right margins together are at least as wide as the page.
+    ExceedsWidth,
+    /// The top and bottom margins together are at least as tall as the page.
+    ExceedsHeight,
+}
+
+impl fmt::Display for MarginError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MarginError::InvalidMargin => write!(f, "margins must be zero or positive numbers"),
+            MarginError::InvalidPageSize => write!(f, "the page size must be a finite number"),
+            MarginError::ExceedsWidth => write!(f, "left and right margins exceed the page width"),
+            MarginError::ExceedsHeight => write!(f, "top and bottom margins exceed the page height"),
+        }
+    }
+}
+
+impl error::Error for MarginError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn points_accessors_convert_each_side() {
+        let margins: Margins = Margins::new(25.4, 12.7, 50.8, 0);
+
+        assert!((margins.left_points() - 72.0).abs() < 1e-9);
+        assert!((margins.right_points() - 36.0).abs() < 1e-9);
+        assert!((margins.top_points() - 144.0).abs() < 1e-9);
+        assert_eq!(margins.bottom_points(), 0.0);
+    }
+
+    #[test]
+    fn validate_accepts_margins_that_fit() {
+        let a5: size::Size = size::Size::new(148, 210);
+
+        assert_eq!(Margins::new(20, 20, 20, 20).validate(&a5), Ok(()));
+        assert_eq!(Margins::new(0, 0, 0, 0).validate(&a5), Ok(()));
+    }
+
+    #[test]
+    fn validate_rejects_oversized_margins() {
+        let a5: size::Size = size::Size::new(148, 210);
+
+        assert_eq!(Margins::new(200, 0, 0, 0).validate(&a5), Err(MarginError::ExceedsWidth));
+        assert_eq!(Margins::new(74, 74, 0, 0).validate(&a5), Err(MarginError::ExceedsWidth));
+        assert_eq!(Margins::new(0, 0, 110, 100).validate(&a5), Err(MarginError::ExceedsHeight));
+    }
+
+    #[test]
+    fn validate_rejects_negative_and_nan_margins() {
+        let a5: size::Size = size::Size::new(148, 210);
+
+        assert_eq!(Margins::new(-10, 0, 0, 0).validate(&a5), Err(MarginError::InvalidMargin));
+        assert_eq!(Margins::new(0, 0, 0, f64::NAN).validate(&a5), Err(MarginError::InvalidMargin));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trips_through_json() {
+        let json: String = serde_json::to_string(&Margins::new(10, 12.5, 20, 0)).unwrap();
+        let margins: Margins = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(json, r#"{"left_margin":10.0,"right_margin":12.5,"top_margin":20.0,"bottom_margin":0.0}"#);
+        assert_eq!(margins.left_margin.millimetres(), 10.0);
+        assert_eq!(margins.right_margin.millimetres(), 12.5);
+        assert_eq!(margins.top_margin.millimetres(), 20.0);
+        assert_eq!(margins.bottom_margin.millimetres(), 0.0);
+    }
+
+    #[test]
+    fn validate_rejects_nan_page_sizes() {
+        let margins: Margins = Margins::new(10, 10, 10, 10);
+
+        assert_eq!(margins.validate(&size::Size::new(f64::NAN, 210)), Err(MarginError::InvalidPageSize));
+        assert_eq!(margins.validate(&size::Size::new(148, f64::NAN)), Err(MarginError::InvalidPageSize));
+        assert_eq!(margins.validate(&size::Size::new(f64::INFINITY, 210)), Err(MarginError::InvalidPageSize));
+    }
 }
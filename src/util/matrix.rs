@@ -0,0 +1,95 @@
+// Copyright (C) 2022 Alexander Rolley
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::format_number;
+
+/// A PDF transformation matrix `[a b c d e f]`, as used by the `cm` and `Tm` operators.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Matrix(pub [f64; 6]);
+
+impl Matrix {
+    pub fn identity() -> Matrix {
+        Matrix([1.0, 0.0, 0.0, 1.0, 0.0, 0.0])
+    }
+
+    pub fn translate(tx: f64, ty: f64) -> Matrix {
+        Matrix([1.0, 0.0, 0.0, 1.0, tx, ty])
+    }
+
+    pub fn scale(sx: f64, sy: f64) -> Matrix {
+        Matrix([sx, 0.0, 0.0, sy, 0.0, 0.0])
+    }
+
+    /// A counter-clockwise rotation, in arc degrees.
+    pub fn rotate(arc_degrees: f64) -> Matrix {
+        let (sin, cos): (f64, f64) = arc_degrees.to_radians().sin_cos();
+
+        Matrix([cos, sin, -sin, cos, 0.0, 0.0])
+    }
+
+    /// Returns the matrix that applies this transformation first and then `other`.
+    pub fn multiply(&self, other: &Matrix) -> Matrix {
+        let [a1, b1, c1, d1, e1, f1] = self.0;
+        let [a2, b2, c2, d2, e2, f2] = other.0;
+
+        Matrix([
+            a1 * a2 + b1 * c2,
+            a1 * b2 + b1 * d2,
+            c1 * a2 + d1 * c2,
+            c1 * b2 + d1 * d2,
+            e1 * a2 + f1 * c2 + e2,
+            e1 * b2 + f1 * d2 + f2,
+        ])
+    }
+
+    /// Returns the `cm` operator that concatenates this matrix to the current transformation matrix.
+    pub fn to_cm_operator(self) -> String {
+        let operands: Vec<String> = self.0.iter().map(|value| format_number(*value)).collect();
+
+        format!("{} cm", operands.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: &Matrix, expected: &Matrix) {
+        for (a, e) in actual.0.iter().zip(expected.0.iter()) {
+            assert!((a - e).abs() < 1e-9, "{:?} is not close to {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn translate_emits_cm_operator() {
+        assert_eq!(Matrix::translate(10.0, 20.0).to_cm_operator(), "1 0 0 1 10 20 cm");
+    }
+
+    #[test]
+    fn rotations_compose() {
+        assert_close(&Matrix::rotate(90.0).multiply(&Matrix::rotate(90.0)), &Matrix::rotate(180.0));
+        assert_eq!(Matrix::rotate(90.0).to_cm_operator(), "0 1 -1 0 0 0 cm");
+    }
+
+    #[test]
+    fn multiply_applies_self_first() {
+        // Translating then scaling also scales the translation.
+        assert_close(&Matrix::translate(1.0, 2.0).multiply(&Matrix::scale(2.0, 3.0)),
+                     &Matrix([2.0, 0.0, 0.0, 3.0, 2.0, 6.0]));
+        assert_close(&Matrix::scale(2.0, 3.0).multiply(&Matrix::translate(1.0, 2.0)),
+                     &Matrix([2.0, 0.0, 0.0, 3.0, 1.0, 2.0]));
+        assert_close(&Matrix::identity().multiply(&Matrix::rotate(30.0)), &Matrix::rotate(30.0));
+    }
+}
@@ -13,9 +13,64 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-#[derive(Debug)]
+use std::fmt;
+use std::ops;
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MM(f64);
 
+impl MM {
+    /// Creates a length from a value in millimeters, rejecting NaN and infinite values.
+    pub fn from_checked(millimeter: f64) -> Option<MM> {
+        if millimeter.is_finite() {
+            Some(MM(millimeter))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the length in millimeters.
+    pub fn millimetres(&self) -> f64 {
+        self.0
+    }
+
+    /// Returns the length in PDF points (1 inch = 25.4 millimeters = 72 points).
+    pub fn points(&self) -> f64 {
+        self.0 * 72.0 / 25.4
+    }
+
+    /// Returns whether two lengths differ by at most `epsilon` millimeters, to allow for floating point
+    /// error after unit conversions.
+    pub fn approx_eq(&self, other: &MM, epsilon: f64) -> bool {
+        (self.0 - other.0).abs() <= epsilon
+    }
+}
+
+impl fmt::Display for MM {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Forward to the f64 implementation so precision and width flags apply to the number.
+        fmt::Display::fmt(&self.0, f)?;
+        f.write_str("mm")
+    }
+}
+
+impl ops::Add for MM {
+    type Output = MM;
+
+    fn add(self, other: MM) -> MM {
+        MM(self.0 + other.0)
+    }
+}
+
+impl ops::Sub for MM {
+    type Output = MM;
+
+    fn sub(self, other: MM) -> MM {
+        MM(self.0 - other.0)
+    }
+}
+
 impl From<i8> for MM {
     fn from(millimeter: i8) -> MM {
         MM(millimeter.into())
@@ -39,3 +94,102 @@ impl From<u16> for MM {
         MM(millimeter.into())
     }
 }
+
+impl From<i32> for MM {
+    fn from(millimeter: i32) -> MM {
+        MM(millimeter.into())
+    }
+}
+
+impl From<u32> for MM {
+    fn from(millimeter: u32) -> MM {
+        MM(millimeter.into())
+    }
+}
+
+// 64-bit integers are exact in an f64 up to 2^53, which is far beyond any sensible length.
+impl From<i64> for MM {
+    fn from(millimeter: i64) -> MM {
+        MM(millimeter as f64)
+    }
+}
+
+impl From<u64> for MM {
+    fn from(millimeter: u64) -> MM {
+        MM(millimeter as f64)
+    }
+}
+
+impl From<f32> for MM {
+    fn from(millimeter: f32) -> MM {
+        MM(millimeter.into())
+    }
+}
+
+impl From<f64> for MM {
+    fn from(millimeter: f64) -> MM {
+        MM(millimeter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_64_bit_integers_is_exact() {
+        assert_eq!(MM::from(9_007_199_254_740_992_i64).0, 9_007_199_254_740_992.0);
+        assert_eq!(MM::from(-9_007_199_254_740_992_i64).0, -9_007_199_254_740_992.0);
+        assert_eq!(MM::from(9_007_199_254_740_992_u64).0, 9_007_199_254_740_992.0);
+    }
+
+    #[test]
+    fn from_checked_rejects_nan_and_infinity() {
+        assert!(MM::from_checked(f64::NAN).is_none());
+        assert!(MM::from_checked(f64::INFINITY).is_none());
+        assert!(MM::from_checked(f64::NEG_INFINITY).is_none());
+        assert_eq!(MM::from_checked(12.5).map(|length| length.0), Some(12.5));
+    }
+
+    #[test]
+    fn points_converts_an_inch_to_72_points() {
+        assert_eq!(MM::from(25.4).points(), 72.0);
+        assert_eq!(MM::from(25.4).millimetres(), 25.4);
+    }
+
+    #[test]
+    fn display_appends_the_unit() {
+        assert_eq!(MM::from(12.5).to_string(), "12.5mm");
+        assert_eq!(MM::from(210).to_string(), "210mm");
+    }
+
+    #[test]
+    fn add_and_sub_work_in_millimeters() {
+        assert_eq!((MM::from(10) + MM::from(2.5)).millimetres(), 12.5);
+        assert_eq!((MM::from(10) - MM::from(2.5)).millimetres(), 7.5);
+        assert_eq!((MM::from(2.5) - MM::from(10)).millimetres(), -7.5);
+    }
+
+    #[test]
+    fn display_honours_precision_and_width() {
+        assert_eq!(format!("{:.2}", MM::from(12.5)), "12.50mm");
+        assert_eq!(format!("{:.0}", MM::from(210.25)), "210mm");
+        assert_eq!(format!("{:>6.1}", MM::from(12.5)), "  12.5mm");
+    }
+
+    #[test]
+    fn inch_round_trips_within_epsilon() {
+        let inch: MM = MM::from(25.4);
+        let round_tripped: MM = MM::from(inch.points() / 72.0 * 25.4);
+
+        assert!(round_tripped.approx_eq(&inch, 1e-9));
+        assert!((round_tripped.millimetres() / 25.4 - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn approx_eq_respects_epsilon() {
+        assert!(MM::from(0.1 + 0.2).approx_eq(&MM::from(0.3), 1e-9));
+        assert!(!MM::from(1).approx_eq(&MM::from(1.1), 0.01));
+        assert!(MM::from(1).approx_eq(&MM::from(1.1), 0.1 + 1e-9));
+    }
+}
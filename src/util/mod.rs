@@ -18,3 +18,37 @@ pub mod mm;
 pub mod margins;
 pub mod rotation;
 pub mod position;
+pub mod matrix;
+
+/// Formats a number for a content stream, rounded to five decimal places without trailing zeros.
+pub(crate) fn format_number(value: f64) -> String {
+    let rounded: String = format!("{:.5}", value);
+    let trimmed: &str = rounded.trim_end_matches('0').trim_end_matches('.');
+
+    // Values that round to zero from below would otherwise be written as `-0`.
+    if trimmed == "-0" {
+        String::from("0")
+    } else {
+        String::from(trimmed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_number_trims_trailing_zeros() {
+        assert_eq!(format_number(10.0), "10");
+        assert_eq!(format_number(2.5), "2.5");
+        assert_eq!(format_number(1.0 / 3.0), "0.33333");
+        assert_eq!(format_number(0.000001), "0");
+    }
+
+    #[test]
+    fn format_number_never_writes_negative_zero() {
+        assert_eq!(format_number(-0.0), "0");
+        assert_eq!(format_number(-0.000001), "0");
+        assert_eq!(format_number(-1.5), "-1.5");
+    }
+}
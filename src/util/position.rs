@@ -17,6 +17,7 @@ use super::mm;
 
 /// The position/z-height of an element in the PDF document, in millimeters/i8 respectively.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Position {
     pub x_coordinate: mm::MM,
     pub y_coordinate: mm::MM,
@@ -33,4 +34,51 @@ impl Position {
             z_index,
         }
     }
+
+    /// Returns this position moved by the given horizontal and vertical distances.
+    pub fn offset(&self, dx: impl Into<mm::MM>, dy: impl Into<mm::MM>) -> Position {
+        Position {
+            x_coordinate: self.x_coordinate + dx.into(),
+            y_coordinate: self.y_coordinate + dy.into(),
+            z_index: self.z_index,
+        }
+    }
+
+    /// Returns this position moved by the coordinates of another position, keeping this z-index.
+    pub fn translate(&self, other: &Position) -> Position {
+        self.offset(other.x_coordinate, other.y_coordinate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offset_moves_by_the_given_distances() {
+        let position: Position = Position::new(10, 10, 0).offset(5, -3);
+
+        assert_eq!(position.x_coordinate.millimetres(), 15.0);
+        assert_eq!(position.y_coordinate.millimetres(), 7.0);
+    }
+
+    #[test]
+    fn translate_adds_coordinates_and_keeps_the_z_index() {
+        let position: Position = Position::new(10, 20, 3).translate(&Position::new(2.5, -5, 7));
+
+        assert_eq!(position.x_coordinate.millimetres(), 12.5);
+        assert_eq!(position.y_coordinate.millimetres(), 15.0);
+        assert_eq!(position.z_index, 3);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trips_through_json() {
+        let json: String = serde_json::to_string(&Position::new(10, 20.5, -2)).unwrap();
+        let position: Position = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(position.x_coordinate.millimetres(), 10.0);
+        assert_eq!(position.y_coordinate.millimetres(), 20.5);
+        assert_eq!(position.z_index, -2);
+    }
 }
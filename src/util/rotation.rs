@@ -13,6 +13,8 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+use super::matrix;
+
 /// The rotation of an element in the PDF document, in arc degrees.
 #[derive(Debug)]
 pub struct Rotation {
@@ -35,4 +37,35 @@ impl Rotation {
             arc_degrees: degrees,
         }
     }
+
+    /// Returns the rotation in radians.
+    pub fn radians(&self) -> f64 {
+        self.arc_degrees.to_radians()
+    }
+
+    /// Returns the transformation matrix for this rotation, for use with the `cm` and `Tm` operators.
+    pub fn to_matrix(&self) -> matrix::Matrix {
+        matrix::Matrix::rotate(self.arc_degrees)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quarter_turn_matrix() {
+        let expected: [f64; 6] = [0.0, 1.0, -1.0, 0.0, 0.0, 0.0];
+
+        for (actual, expected) in Rotation::new(90.0).to_matrix().0.iter().zip(expected.iter()) {
+            assert!((actual - expected).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn radians_uses_normalised_degrees() {
+        assert!((Rotation::new(90.0).radians() - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+        assert!((Rotation::new(270.0).radians() + std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+        assert_eq!(Rotation::new(0.0).radians(), 0.0);
+    }
 }
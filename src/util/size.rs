@@ -13,10 +13,13 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+use std::fmt;
+
 use super::mm;
 
 /// The size of an element in the PDF document, in millimeters.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Size {
     pub width: mm::MM,
     pub height: mm::MM,
@@ -29,4 +32,45 @@ impl Size {
             height: height.into(),
         }
     }
+
+    /// Returns the width.
+    pub fn width(&self) -> mm::MM {
+        self.width
+    }
+
+    /// Returns the height.
+    pub fn height(&self) -> mm::MM {
+        self.height
+    }
+
+    /// Returns the area in square millimeters.
+    pub fn area(&self) -> f64 {
+        self.width.millimetres() * self.height.millimetres()
+    }
+}
+
+impl fmt::Display for Size {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} x {}", self.width, self.height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_shows_width_and_height() {
+        let a4: Size = Size::new(210, 297);
+
+        assert_eq!(a4.to_string(), "210mm x 297mm");
+        assert_eq!(a4.width().millimetres(), 210.0);
+        assert_eq!(a4.height().millimetres(), 297.0);
+    }
+
+    #[test]
+    fn area_is_in_square_millimeters() {
+        assert_eq!(Size::new(210, 297).area(), 62370.0);
+        assert_eq!(Size::new(0, 297).area(), 0.0);
+    }
 }